@@ -8,6 +8,8 @@ fn main() {
         println!("orientation: {:?}", device.orientation());
         println!("position: {:?}", device.position());
         println!("resolutions: {:?}", device.resolutions());
+        println!("vendor_id: {:?}", device.vendor_id());
+        println!("product_id: {:?}", device.product_id());
         println!();
     }
 }
@@ -0,0 +1,15 @@
+use capture_device_info::mf::capture_devices;
+use capture_device_info::CaptureDeviceInfo;
+
+fn main() {
+    for device in capture_devices().unwrap() {
+        println!("name: {}", device.name());
+        println!("description: {}", device.description());
+        println!("orientation: {:?}", device.orientation());
+        println!("position: {:?}", device.position());
+        println!("resolutions: {:?}", device.resolutions());
+        println!("vendor_id: {:?}", device.vendor_id());
+        println!("product_id: {:?}", device.product_id());
+        println!();
+    }
+}
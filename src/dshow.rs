@@ -13,13 +13,20 @@ use windows::Win32::System::Com::StructuredStorage::*;
 use windows::Win32::System::Com::*;
 use windows::Win32::System::Ole::*;
 
-use crate::{CaptureDeviceInfo, CaptureDevicePosition, CaptureDeviceResolution};
+use crate::{
+    CaptureDeviceInfo, CaptureDevicePixelFormat, CaptureDevicePosition, CaptureDeviceResolution,
+    EnumerationOptions,
+};
 
 /// Capture device information from DirectShow.
 pub struct DirectShowCaptureDevice {
     name: String,
     description: String,
     resolution: Vec<CaptureDeviceResolution>,
+    #[cfg(feature = "position")]
+    orientation: Option<i32>,
+    #[cfg(feature = "position")]
+    position: Option<CaptureDevicePosition>,
 }
 
 impl CaptureDeviceInfo for DirectShowCaptureDevice {
@@ -32,11 +39,25 @@ impl CaptureDeviceInfo for DirectShowCaptureDevice {
     }
 
     fn orientation(&self) -> Option<i32> {
-        None
+        #[cfg(feature = "position")]
+        {
+            self.orientation
+        }
+        #[cfg(not(feature = "position"))]
+        {
+            None
+        }
     }
 
     fn position(&self) -> Option<CaptureDevicePosition> {
-        None
+        #[cfg(feature = "position")]
+        {
+            self.position
+        }
+        #[cfg(not(feature = "position"))]
+        {
+            None
+        }
     }
 
     fn resolutions(&self) -> &[CaptureDeviceResolution] {
@@ -171,6 +192,30 @@ impl MediaType {
             None
         }
     }
+
+    fn format(&self) -> CaptureDevicePixelFormat {
+        let mt = self.as_ref();
+        let subtype = mt.subtype;
+        if subtype == MEDIASUBTYPE_YUY2 {
+            CaptureDevicePixelFormat::Yuy2
+        } else if subtype == MEDIASUBTYPE_MJPG {
+            CaptureDevicePixelFormat::Mjpg
+        } else if subtype == MEDIASUBTYPE_RGB24 {
+            CaptureDevicePixelFormat::Rgb24
+        } else if subtype == MEDIASUBTYPE_RGB32 {
+            CaptureDevicePixelFormat::Rgb32
+        } else if subtype == MEDIASUBTYPE_NV12 {
+            CaptureDevicePixelFormat::Nv12
+        } else if subtype == MEDIASUBTYPE_I420 {
+            CaptureDevicePixelFormat::I420
+        } else {
+            let fourcc = self
+                .bitmap_info()
+                .map(|bi| bi.biCompression)
+                .unwrap_or(subtype.data1);
+            CaptureDevicePixelFormat::Other(fourcc)
+        }
+    }
 }
 
 impl Drop for MediaType {
@@ -219,6 +264,98 @@ impl Iterator for MediaTypeIterator {
     }
 }
 
+/// Iterates the stream capabilities exposed by a pin's `IAMStreamConfig`,
+/// which (unlike plain `IEnumMediaTypes`) also reports the min/max frame
+/// interval supported at each media type.
+struct StreamConfigIterator {
+    stream_config: IAMStreamConfig,
+    count: i32,
+    index: i32,
+}
+
+impl StreamConfigIterator {
+    pub fn from_pin(pin: &IPin) -> Option<Self> {
+        let stream_config: IAMStreamConfig = pin.cast().ok()?;
+        let mut count = 0;
+        let mut size = 0;
+        unsafe { stream_config.GetNumberOfCapabilities(&mut count, &mut size) }.ok()?;
+        if size as usize != std::mem::size_of::<VIDEO_STREAM_CONFIG_CAPS>() {
+            // Not a video pin, or a capability layout we don't understand.
+            return None;
+        }
+        Some(Self {
+            stream_config,
+            count,
+            index: 0,
+        })
+    }
+}
+
+impl Iterator for StreamConfigIterator {
+    type Item = (MediaType, VIDEO_STREAM_CONFIG_CAPS);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let mut mt_ptr: *mut AM_MEDIA_TYPE = std::ptr::null_mut();
+        let mut caps = VIDEO_STREAM_CONFIG_CAPS::default();
+        let hr = unsafe {
+            self.stream_config.GetStreamCaps(
+                self.index,
+                &mut mt_ptr,
+                &mut caps as *mut _ as *mut u8,
+            )
+        };
+        self.index += 1;
+
+        if hr.is_ok() {
+            let mt = MediaType::new(NonNull::new(mt_ptr)?);
+            Some((mt, caps))
+        } else {
+            None
+        }
+    }
+}
+
+/// Converts a `VIDEO_STREAM_CONFIG_CAPS` frame interval range (in 100ns units)
+/// to a `(min, max)` frame rate range, rounded to 0.01.
+fn frame_rate_range(caps: &VIDEO_STREAM_CONFIG_CAPS) -> Option<(f64, f64)> {
+    if caps.MinFrameInterval <= 0 || caps.MaxFrameInterval <= 0 {
+        return None;
+    }
+    let max_frame_rate = 10_000_000.0 / caps.MinFrameInterval as f64;
+    let min_frame_rate = 10_000_000.0 / caps.MaxFrameInterval as f64;
+    Some((
+        (min_frame_rate * 100.0).round() / 100.0,
+        (max_frame_rate * 100.0).round() / 100.0,
+    ))
+}
+
+fn resolution_from_media_type(
+    media_type: &MediaType,
+    frame_rate_range: Option<(f64, f64)>,
+) -> Option<CaptureDeviceResolution> {
+    let bi = media_type.bitmap_info()?;
+    let width = bi.biWidth as u32;
+    let height = bi.biHeight.unsigned_abs();
+    let frame_rate = media_type.frame_rate()?;
+    let format = media_type.format();
+    let (min_frame_rate, max_frame_rate) = match frame_rate_range {
+        Some((min, max)) => (Some(min), Some(max)),
+        None => (None, None),
+    };
+    Some(CaptureDeviceResolution {
+        width,
+        height,
+        frame_rate,
+        format,
+        min_frame_rate,
+        max_frame_rate,
+    })
+}
+
 /// Get capture devices from DirectShow.
 ///
 /// # Examples
@@ -237,92 +374,135 @@ impl Iterator for MediaTypeIterator {
 /// # }
 /// ```
 pub fn capture_devices() -> Result<impl Iterator<Item = DirectShowCaptureDevice>> {
+    capture_devices_impl(None)
+}
+
+/// Like [`capture_devices`], but skips devices matching `options` before probing
+/// their pins/resolutions.
+///
+/// This prevents hangs and duplicate entries when enumerating on machines with
+/// crash-prone or redundant virtual camera software installed (see
+/// [`EnumerationOptions`] for the default blocklist). Because the check happens
+/// before a blocked device's output pins are bound and queried, it can skip a
+/// device that would otherwise hang during that probing.
+pub fn capture_devices_filtered(
+    options: &EnumerationOptions,
+) -> Result<impl Iterator<Item = DirectShowCaptureDevice>> {
+    capture_devices_impl(Some(options))
+}
+
+fn capture_devices_impl(
+    options: Option<&EnumerationOptions>,
+) -> Result<impl Iterator<Item = DirectShowCaptureDevice>> {
     unsafe { CoInitializeEx(None, COINIT_MULTITHREADED)? };
 
-    let devices: Vec<_> = MonikerIterator::enumerate_devices()?
-        .map(|moniker| {
-            // get property bag
-            let mut prop_bag: Option<IPropertyBag> = None;
-            unsafe {
-                moniker.BindToStorage(
-                    InParam::null(),
-                    InParam::null(),
-                    &IPropertyBag::IID,
-                    &mut prop_bag as *mut _ as *mut _,
-                )?;
+    #[cfg(feature = "position")]
+    let enclosures = crate::enclosure::enumerate_enclosures().unwrap_or_default();
+
+    let mut devices = Vec::new();
+    for moniker in MonikerIterator::enumerate_devices()? {
+        // get property bag
+        let mut prop_bag: Option<IPropertyBag> = None;
+        unsafe {
+            moniker.BindToStorage(
+                InParam::null(),
+                InParam::null(),
+                &IPropertyBag::IID,
+                &mut prop_bag as *mut _ as *mut _,
+            )?;
+        }
+        let prop_bag = prop_bag.unwrap();
+
+        // initialize variant
+        let mut variant = Default::default();
+
+        // get description from "Description" or "FriendlyName"
+        let description = unsafe {
+            VariantInit(&mut variant);
+            if prop_bag
+                .Read(&"Description".into(), &mut variant, InParam::null())
+                .is_err()
+            {
+                prop_bag.Read(&"FriendlyName".into(), &mut variant, InParam::null())?;
             }
-            let prop_bag = prop_bag.unwrap();
-
-            // initialize variant
-            let mut variant = Default::default();
-
-            // get description from "Description" or "FriendlyName"
-            let description = unsafe {
-                VariantInit(&mut variant);
-                if prop_bag
-                    .Read(&"Description".into(), &mut variant, InParam::null())
-                    .is_err()
-                {
-                    prop_bag.Read(&"FriendlyName".into(), &mut variant, InParam::null())?;
-                }
-                // see: https://github.com/microsoft/windows-rs/issues/539
-                let desc = variant.Anonymous.Anonymous.Anonymous.bstrVal.to_string();
+            // see: https://github.com/microsoft/windows-rs/issues/539
+            let desc = variant.Anonymous.Anonymous.Anonymous.bstrVal.to_string();
+            VariantClear(&mut variant)?;
+            desc
+        };
+
+        // get device path from "DevicePath"
+        let device_path = unsafe {
+            VariantInit(&mut variant);
+            if prop_bag
+                .Read(&"DevicePath".into(), &mut variant, InParam::null())
+                .is_ok()
+            {
+                let path = variant.Anonymous.Anonymous.Anonymous.bstrVal.to_string();
                 VariantClear(&mut variant)?;
-                desc
-            };
-
-            // get device path from "DevicePath"
-            let device_path = unsafe {
-                VariantInit(&mut variant);
-                if prop_bag
-                    .Read(&"DevicePath".into(), &mut variant, InParam::null())
-                    .is_ok()
-                {
-                    let path = variant.Anonymous.Anonymous.Anonymous.bstrVal.to_string();
-                    VariantClear(&mut variant)?;
-                    path
-                } else {
-                    String::new()
-                }
-            };
-
-            // get pins
-            let mut resolution = HashSet::new();
-            for pin in PinIterator::enumerate_pins(&moniker)? {
-                // filter output pins
-                let pin_info = unsafe { pin.QueryPinInfo() }?;
-                if pin_info.dir != PINDIR_OUTPUT {
-                    continue;
-                }
+                path
+            } else {
+                String::new()
+            }
+        };
+
+        // skip blocked devices before probing their pins/resolutions
+        if let Some(options) = options {
+            let vendor_id = crate::usb_id_from_path(&device_path, "vid_");
+            let product_id = crate::usb_id_from_path(&device_path, "pid_");
+            if options.excludes(&device_path, &description, vendor_id, product_id) {
+                continue;
+            }
+        }
+
+        // get pins
+        let mut resolution = HashSet::new();
+        for pin in PinIterator::enumerate_pins(&moniker)? {
+            // filter output pins
+            let pin_info = unsafe { pin.QueryPinInfo() }?;
+            if pin_info.dir != PINDIR_OUTPUT {
+                continue;
+            }
 
-                // get media types
+            // get media types, preferring IAMStreamConfig for the fps range it reports
+            if let Some(stream_config) = StreamConfigIterator::from_pin(&pin) {
+                for (media_type, caps) in stream_config {
+                    if let Some(r) =
+                        resolution_from_media_type(&media_type, frame_rate_range(&caps))
+                    {
+                        resolution.insert(r);
+                    }
+                }
+            } else {
                 for media_type in MediaTypeIterator::enumerate_media_types(&pin)? {
-                    if let Some(bi) = media_type.bitmap_info() {
-                        let width = bi.biWidth as u32;
-                        let height = bi.biHeight.unsigned_abs();
-                        let frame_rate = media_type.frame_rate().unwrap();
-                        resolution.insert(CaptureDeviceResolution {
-                            width,
-                            height,
-                            frame_rate,
-                        });
+                    if let Some(r) = resolution_from_media_type(&media_type, None) {
+                        resolution.insert(r);
                     }
                 }
             }
-            let mut resolution: Vec<_> = resolution.into_iter().collect();
-            resolution.sort_unstable_by(|a, b| {
-                let a = (a.width as f64) * (a.height as f64) * a.frame_rate;
-                let b = (b.width as f64) * (b.height as f64) * b.frame_rate;
-                b.partial_cmp(&a).unwrap()
-            });
-
-            Ok(DirectShowCaptureDevice {
-                name: device_path,
-                description,
-                resolution,
-            })
-        })
-        .collect::<Result<_>>()?;
+        }
+        let mut resolution: Vec<_> = resolution.into_iter().collect();
+        resolution.sort_unstable_by(|a, b| {
+            let a = (a.width as f64) * (a.height as f64) * a.frame_rate;
+            let b = (b.width as f64) * (b.height as f64) * b.frame_rate;
+            b.partial_cmp(&a).unwrap()
+        });
+
+        #[cfg(feature = "position")]
+        let (orientation, position) = crate::enclosure::find_enclosure(&enclosures, &device_path)
+            .map(|e| (e.orientation, e.position))
+            .unwrap_or((None, None));
+
+        devices.push(DirectShowCaptureDevice {
+            name: device_path,
+            description,
+            resolution,
+            #[cfg(feature = "position")]
+            orientation,
+            #[cfg(feature = "position")]
+            position,
+        });
+    }
 
     unsafe { CoUninitialize() };
 
@@ -0,0 +1,75 @@
+//! Resolve camera position/orientation using WinRT `DeviceInformation` enclosure data.
+//!
+//! Neither DirectShow nor Media Foundation expose where a camera sensor sits on
+//! the device, but `Windows.Devices.Enumeration` does via `EnclosureLocation`.
+//! This enumerates that information once and matches it back to a DirectShow/
+//! Media Foundation device by its device path / symbolic link.
+
+use std::collections::HashMap;
+
+use windows::core::*;
+use windows::Devices::Enumeration::{DeviceClass, DeviceInformation, Panel};
+
+use crate::CaptureDevicePosition;
+
+/// The enclosure data available for a single capture device.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Enclosure {
+    pub position: Option<CaptureDevicePosition>,
+    pub orientation: Option<i32>,
+}
+
+/// Enumerates enclosure data for every video capture device, keyed by device id.
+pub(crate) fn enumerate_enclosures() -> Result<HashMap<String, Enclosure>> {
+    let devices = DeviceInformation::FindAllAsyncDeviceClass(DeviceClass::VideoCapture)?.get()?;
+
+    let mut enclosures = HashMap::new();
+    for device in devices {
+        let Ok(enclosure_location) = device.EnclosureLocation() else {
+            // No enclosure info, e.g. USB externals and virtual cameras.
+            continue;
+        };
+
+        let position = match enclosure_location.Panel() {
+            Ok(Panel::Front) => Some(CaptureDevicePosition::Front),
+            Ok(Panel::Back) => Some(CaptureDevicePosition::Back),
+            _ => None,
+        };
+        let orientation = enclosure_location
+            .RotationAngleInDegreesClockwise()
+            .ok()
+            .map(|angle| angle as i32);
+
+        let id = device.Id()?.to_string();
+        enclosures.insert(
+            id.to_ascii_lowercase(),
+            Enclosure {
+                position,
+                orientation,
+            },
+        );
+    }
+
+    Ok(enclosures)
+}
+
+/// Finds the enclosure entry whose id overlaps `device_path`, case-insensitively.
+///
+/// WinRT and DirectShow/Media Foundation device ids refer to the same underlying
+/// device but are not guaranteed to match byte-for-byte, so a substring match in
+/// either direction is used instead of equality.
+pub(crate) fn find_enclosure<'a>(
+    enclosures: &'a HashMap<String, Enclosure>,
+    device_path: &str,
+) -> Option<&'a Enclosure> {
+    if device_path.is_empty() {
+        return None;
+    }
+    let device_path = device_path.to_ascii_lowercase();
+    enclosures
+        .iter()
+        .find(|(id, _)| {
+            !id.is_empty() && (id.contains(&device_path) || device_path.contains(id.as_str()))
+        })
+        .map(|(_, enclosure)| enclosure)
+}
@@ -8,6 +8,8 @@
 //! | Feature | Platform | Description |
 //! | ------- | -------- | ----------- |
 //! | `dshow` | Windows  | Use DirectShow API. |
+//! | `mf`    | Windows  | Use Media Foundation API. Preferred over `dshow` on modern Windows. |
+//! | `position` | Windows | Resolve [`orientation`](CaptureDeviceInfo::orientation) and [`position`](CaptureDeviceInfo::position) via WinRT `DeviceInformation`. Requires `dshow` and/or `mf`; adds no sub-module of its own. |
 //!
 //! Each feature enables the corresponding sub-module,
 //! which exposes the `capture_devices` method.
@@ -39,6 +41,31 @@
 //!     println!("orientation: {:?}", device.orientation());
 //!     println!("position: {:?}", device.position());
 //!     println!("resolutions: {:?}", device.resolutions());
+//!     println!("vendor_id: {:?}", device.vendor_id());
+//!     println!("product_id: {:?}", device.product_id());
+//!     println!();
+//! }
+//! # }
+//! ```
+//!
+//! ### Media Foundation
+//!
+//! ```rust
+//! use capture_device_info::CaptureDeviceInfo;
+//! // To use Media Foundation API, enable the `mf` feature.
+//! # #[cfg(feature = "mf")]
+//! use capture_device_info::mf::capture_devices;
+//!
+//! # #[cfg(feature = "mf")]
+//! # fn main() {
+//! for device in capture_devices().unwrap() {
+//!     println!("name: {}", device.name());
+//!     println!("description: {}", device.description());
+//!     println!("orientation: {:?}", device.orientation());
+//!     println!("position: {:?}", device.position());
+//!     println!("resolutions: {:?}", device.resolutions());
+//!     println!("vendor_id: {:?}", device.vendor_id());
+//!     println!("product_id: {:?}", device.product_id());
 //!     println!();
 //! }
 //! # }
@@ -51,6 +78,10 @@ use std::hash::{Hash, Hasher};
 
 #[cfg(feature = "dshow")]
 pub mod dshow;
+#[cfg(feature = "position")]
+mod enclosure;
+#[cfg(feature = "mf")]
+pub mod mf;
 
 /// The information of a capture device.
 ///
@@ -86,6 +117,208 @@ pub trait CaptureDeviceInfo {
     ///
     /// The value may be unspecified, in which case an empty vector is returned.
     fn resolutions(&self) -> &[CaptureDeviceResolution];
+
+    /// Returns the USB vendor ID of the camera, parsed from [`name`](Self::name).
+    ///
+    /// The value may be unavailable (e.g. for virtual cameras), in which case
+    /// [`None`] is returned.
+    fn vendor_id(&self) -> Option<u16> {
+        usb_id_from_path(self.name(), "vid_")
+    }
+
+    /// Returns the USB product ID of the camera, parsed from [`name`](Self::name).
+    ///
+    /// The value may be unavailable (e.g. for virtual cameras), in which case
+    /// [`None`] is returned.
+    fn product_id(&self) -> Option<u16> {
+        usb_id_from_path(self.name(), "pid_")
+    }
+}
+
+/// Options controlling which devices `capture_devices_filtered` returns.
+///
+/// Some software installs virtual cameras that are crash-prone, redundant, or
+/// otherwise unwanted in an enumeration (screen-capture adapters, mirroring
+/// adapters, etc.). [`EnumerationOptions::default`] skips a list of known
+/// offenders; construct a custom value to tighten or loosen that behavior.
+#[derive(Debug, Clone)]
+pub struct EnumerationOptions {
+    /// Skip devices whose [`description`](CaptureDeviceInfo::description) contains
+    /// any of these substrings, case-insensitively.
+    pub exclude_description_substrings: Vec<String>,
+    /// Skip devices whose (vendor ID, product ID) pair matches any of these.
+    pub exclude_vid_pid: Vec<(u16, u16)>,
+    /// Skip devices with an empty [`name`](CaptureDeviceInfo::name), which is
+    /// typically a sign of a virtual camera with no real device path.
+    pub exclude_empty_path: bool,
+}
+
+/// Names of known crash-prone or redundant virtual camera adapters, skipped by
+/// [`EnumerationOptions::default`].
+const DEFAULT_BLOCKED_NAME_SUBSTRINGS: &[&str] = &[
+    "obs virtual camera",
+    "obs-camera",
+    "e2esoft vcam",
+    "manycam virtual webcam",
+    "screen capture recorder",
+    "droidcam virtual",
+];
+
+impl Default for EnumerationOptions {
+    fn default() -> Self {
+        Self {
+            exclude_description_substrings: DEFAULT_BLOCKED_NAME_SUBSTRINGS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            exclude_vid_pid: Vec::new(),
+            exclude_empty_path: true,
+        }
+    }
+}
+
+impl EnumerationOptions {
+    /// Returns `true` if a device with the given info should be skipped.
+    pub(crate) fn excludes(
+        &self,
+        name: &str,
+        description: &str,
+        vendor_id: Option<u16>,
+        product_id: Option<u16>,
+    ) -> bool {
+        if self.exclude_empty_path && name.is_empty() {
+            return true;
+        }
+
+        let description = description.to_ascii_lowercase();
+        if self
+            .exclude_description_substrings
+            .iter()
+            .any(|s| description.contains(&s.to_ascii_lowercase()))
+        {
+            return true;
+        }
+
+        if let (Some(vendor_id), Some(product_id)) = (vendor_id, product_id) {
+            if self.exclude_vid_pid.contains(&(vendor_id, product_id)) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod enumeration_options_tests {
+    use super::EnumerationOptions;
+
+    fn options() -> EnumerationOptions {
+        EnumerationOptions {
+            exclude_description_substrings: vec!["obs virtual camera".to_string()],
+            exclude_vid_pid: vec![(0x046d, 0x082d)],
+            exclude_empty_path: true,
+        }
+    }
+
+    #[test]
+    fn excludes_empty_path() {
+        let options = options();
+        assert!(options.excludes("", "Some Camera", None, None));
+    }
+
+    #[test]
+    fn keeps_empty_path_when_disabled() {
+        let options = EnumerationOptions {
+            exclude_empty_path: false,
+            ..options()
+        };
+        assert!(!options.excludes("", "Some Camera", None, None));
+    }
+
+    #[test]
+    fn excludes_blocked_description_case_insensitively() {
+        let options = options();
+        assert!(options.excludes("\\\\?\\path", "OBS Virtual Camera", None, None));
+    }
+
+    #[test]
+    fn excludes_blocked_vid_pid() {
+        let options = options();
+        assert!(options.excludes("\\\\?\\path", "Some Camera", Some(0x046d), Some(0x082d)));
+    }
+
+    #[test]
+    fn keeps_unmatched_vid_pid() {
+        let options = options();
+        assert!(!options.excludes("\\\\?\\path", "Some Camera", Some(0x046d), Some(0x0001)));
+    }
+
+    #[test]
+    fn keeps_device_with_no_matches() {
+        let options = options();
+        assert!(!options.excludes("\\\\?\\path", "Some Camera", None, None));
+    }
+
+    #[test]
+    fn default_blocklist_excludes_known_virtual_cameras() {
+        let options = EnumerationOptions::default();
+        assert!(options.excludes("\\\\?\\path", "OBS Virtual Camera", None, None));
+        assert!(!options.excludes("\\\\?\\path", "Logitech BRIO", None, None));
+    }
+}
+
+/// Finds `prefix` in `path` case-insensitively and parses the following 4 hex digits.
+///
+/// This mirrors how Chromium extracts the USB VID/PID from a Windows device path,
+/// e.g. `\\?\usb#vid_046d&pid_082d&mi_00#...`.
+pub(crate) fn usb_id_from_path(path: &str, prefix: &str) -> Option<u16> {
+    let lower = path.to_ascii_lowercase();
+    let index = lower.find(prefix)? + prefix.len();
+    let digits = path.get(index..index + 4)?;
+    u16::from_str_radix(digits, 16).ok()
+}
+
+#[cfg(test)]
+mod usb_id_from_path_tests {
+    use super::usb_id_from_path;
+
+    #[test]
+    fn parses_vid_and_pid() {
+        let path = r"\\?\usb#vid_046d&pid_082d&mi_00#...";
+        assert_eq!(usb_id_from_path(path, "vid_"), Some(0x046d));
+        assert_eq!(usb_id_from_path(path, "pid_"), Some(0x082d));
+    }
+
+    #[test]
+    fn matches_prefix_case_insensitively() {
+        let path = r"\\?\usb#VID_046D&PID_082D&mi_00#...";
+        assert_eq!(usb_id_from_path(path, "vid_"), Some(0x046d));
+        assert_eq!(usb_id_from_path(path, "pid_"), Some(0x082d));
+    }
+
+    #[test]
+    fn returns_none_when_prefix_missing() {
+        let path = r"\\?\swd\virtualcamera\virtualcam";
+        assert_eq!(usb_id_from_path(path, "vid_"), None);
+    }
+
+    #[test]
+    fn returns_none_on_truncated_hex() {
+        let path = r"\\?\usb#vid_04&pid_082d#...";
+        assert_eq!(usb_id_from_path(path, "vid_"), None);
+    }
+
+    #[test]
+    fn returns_none_on_non_hex_digits() {
+        let path = r"\\?\usb#vid_zzzz&pid_082d#...";
+        assert_eq!(usb_id_from_path(path, "vid_"), None);
+    }
+
+    #[test]
+    fn returns_none_for_empty_path() {
+        assert_eq!(usb_id_from_path("", "vid_"), None);
+    }
 }
 
 /// The physical position of the camera on the hardware system.
@@ -105,7 +338,38 @@ pub struct CaptureDeviceResolution {
     /// Height of a frame.
     pub height: u32,
     /// Frame rate (per second). Rounded to 0.01.
+    ///
+    /// When the device reports a continuous range via
+    /// [`min_frame_rate`](Self::min_frame_rate)/[`max_frame_rate`](Self::max_frame_rate),
+    /// this is the default/average frame rate rather than the only one supported.
     pub frame_rate: f64,
+    /// Pixel format of the frame.
+    pub format: CaptureDevicePixelFormat,
+    /// The minimum frame rate (per second) supported at this resolution, if the
+    /// device reports a continuous range. Rounded to 0.01.
+    pub min_frame_rate: Option<f64>,
+    /// The maximum frame rate (per second) supported at this resolution, if the
+    /// device reports a continuous range. Rounded to 0.01.
+    pub max_frame_rate: Option<f64>,
+}
+
+/// The pixel format (FourCC) of a capture device resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaptureDevicePixelFormat {
+    /// YUY2 (packed YUV 4:2:2).
+    Yuy2,
+    /// NV12 (planar YUV 4:2:0).
+    Nv12,
+    /// Motion JPEG.
+    Mjpg,
+    /// Packed RGB, 24 bits per pixel.
+    Rgb24,
+    /// Packed RGB, 32 bits per pixel.
+    Rgb32,
+    /// I420 (planar YUV 4:2:0).
+    I420,
+    /// A format not recognized by this crate, identified by its raw FourCC.
+    Other(u32),
 }
 
 /// The time unit used for frame rate (10ms).
@@ -115,6 +379,16 @@ impl CaptureDeviceResolution {
     fn frame_rate_by_units(&self) -> i64 {
         (self.frame_rate * FRAME_RATE_TIME_UNIT_PER_SECOND) as i64
     }
+
+    fn min_frame_rate_by_units(&self) -> Option<i64> {
+        self.min_frame_rate
+            .map(|fps| (fps * FRAME_RATE_TIME_UNIT_PER_SECOND) as i64)
+    }
+
+    fn max_frame_rate_by_units(&self) -> Option<i64> {
+        self.max_frame_rate
+            .map(|fps| (fps * FRAME_RATE_TIME_UNIT_PER_SECOND) as i64)
+    }
 }
 
 impl PartialEq for CaptureDeviceResolution {
@@ -122,6 +396,9 @@ impl PartialEq for CaptureDeviceResolution {
         self.width == other.width
             && self.height == other.height
             && self.frame_rate_by_units() == other.frame_rate_by_units()
+            && self.format == other.format
+            && self.min_frame_rate_by_units() == other.min_frame_rate_by_units()
+            && self.max_frame_rate_by_units() == other.max_frame_rate_by_units()
     }
 }
 
@@ -132,5 +409,8 @@ impl Hash for CaptureDeviceResolution {
         self.width.hash(state);
         self.height.hash(state);
         self.frame_rate_by_units().hash(state);
+        self.format.hash(state);
+        self.min_frame_rate_by_units().hash(state);
+        self.max_frame_rate_by_units().hash(state);
     }
 }
@@ -0,0 +1,288 @@
+//! Get capture devices from Media Foundation.
+//!
+//! DirectShow is considered legacy by Microsoft and can be unstable on modern
+//! Windows, so prefer this module when possible and fall back to [`crate::dshow`]
+//! only when Media Foundation enumeration fails.
+//!
+//! See: [MSDN](https://learn.microsoft.com/en-us/windows/win32/medfound/audio-video-capture-in-media-foundation)
+
+use std::collections::HashSet;
+
+use windows::core::*;
+use windows::Win32::Media::MediaFoundation::*;
+use windows::Win32::System::Com::*;
+
+use crate::{
+    CaptureDeviceInfo, CaptureDevicePixelFormat, CaptureDevicePosition, CaptureDeviceResolution,
+    EnumerationOptions,
+};
+
+/// Capture device information from Media Foundation.
+pub struct MediaFoundationCaptureDevice {
+    name: String,
+    description: String,
+    resolution: Vec<CaptureDeviceResolution>,
+    #[cfg(feature = "position")]
+    orientation: Option<i32>,
+    #[cfg(feature = "position")]
+    position: Option<CaptureDevicePosition>,
+}
+
+impl CaptureDeviceInfo for MediaFoundationCaptureDevice {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn orientation(&self) -> Option<i32> {
+        #[cfg(feature = "position")]
+        {
+            self.orientation
+        }
+        #[cfg(not(feature = "position"))]
+        {
+            None
+        }
+    }
+
+    fn position(&self) -> Option<CaptureDevicePosition> {
+        #[cfg(feature = "position")]
+        {
+            self.position
+        }
+        #[cfg(not(feature = "position"))]
+        {
+            None
+        }
+    }
+
+    fn resolutions(&self) -> &[CaptureDeviceResolution] {
+        &self.resolution
+    }
+}
+
+struct DeviceSourceIterator {
+    activates: std::vec::IntoIter<IMFActivate>,
+}
+
+impl DeviceSourceIterator {
+    pub fn enumerate_devices() -> Result<Self> {
+        let activates = unsafe {
+            let mut attributes = None;
+            MFCreateAttributes(&mut attributes, 1)?;
+            let attributes = attributes
+                .ok_or_else(|| Error::new(E_FAIL, "Failed to create MF attribute store".into()))?;
+            attributes.SetGUID(
+                &MF_DEVSOURCE_ATTRIBUTE_SOURCE_TYPE,
+                &MF_DEVSOURCE_ATTRIBUTE_SOURCE_TYPE_VIDCAP_GUID,
+            )?;
+
+            let mut sources: *mut Option<IMFActivate> = std::ptr::null_mut();
+            let mut count = 0u32;
+            MFEnumDeviceSources(&attributes, &mut sources, &mut count)?;
+
+            // `MFEnumDeviceSources` hands over one already-AddRef'd `IMFActivate` per
+            // slot, so take ownership of each via `Option::take` (no extra AddRef)
+            // instead of cloning, or the original reference would never be released.
+            let devices = std::slice::from_raw_parts_mut(sources, count as usize)
+                .iter_mut()
+                .filter_map(Option::take)
+                .collect::<Vec<_>>();
+            CoTaskMemFree(Some(sources as *mut _));
+            devices
+        };
+
+        Ok(Self {
+            activates: activates.into_iter(),
+        })
+    }
+}
+
+impl Iterator for DeviceSourceIterator {
+    type Item = IMFActivate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.activates.next()
+    }
+}
+
+fn get_allocated_string(activate: &IMFActivate, key: &GUID) -> Result<String> {
+    unsafe {
+        let mut buf = PWSTR::null();
+        let mut len = 0u32;
+        activate.GetAllocatedString(key, &mut buf, &mut len)?;
+        let s = buf.to_string();
+        CoTaskMemFree(Some(buf.0 as *mut _));
+        Ok(s?)
+    }
+}
+
+fn frame_rate(media_type: &IMFMediaType) -> Option<f64> {
+    let (num, den) = unsafe { MFGetAttributeRatio(media_type, &MF_MT_FRAME_RATE) }.ok()?;
+    if den == 0 {
+        return None;
+    }
+    let fps = num as f64 / den as f64;
+    Some((fps * 100.0).round() / 100.0)
+}
+
+fn frame_size(media_type: &IMFMediaType) -> Option<(u32, u32)> {
+    unsafe { MFGetAttributeSize(media_type, &MF_MT_FRAME_SIZE) }.ok()
+}
+
+fn format(media_type: &IMFMediaType) -> CaptureDevicePixelFormat {
+    let subtype = unsafe { media_type.GetGUID(&MF_MT_SUBTYPE) };
+    match subtype {
+        Ok(subtype) if subtype == MFVideoFormat_YUY2 => CaptureDevicePixelFormat::Yuy2,
+        Ok(subtype) if subtype == MFVideoFormat_NV12 => CaptureDevicePixelFormat::Nv12,
+        Ok(subtype) if subtype == MFVideoFormat_MJPG => CaptureDevicePixelFormat::Mjpg,
+        Ok(subtype) if subtype == MFVideoFormat_RGB24 => CaptureDevicePixelFormat::Rgb24,
+        Ok(subtype) if subtype == MFVideoFormat_RGB32 => CaptureDevicePixelFormat::Rgb32,
+        Ok(subtype) if subtype == MFVideoFormat_I420 => CaptureDevicePixelFormat::I420,
+        Ok(subtype) => CaptureDevicePixelFormat::Other(subtype.data1),
+        Err(_) => CaptureDevicePixelFormat::Other(0),
+    }
+}
+
+fn enumerate_resolutions(activate: &IMFActivate) -> Result<Vec<CaptureDeviceResolution>> {
+    let mut resolution = HashSet::new();
+    unsafe {
+        let source: IMFMediaSource = activate.ActivateObject()?;
+        let pd = source.CreatePresentationDescriptor()?;
+
+        let stream_count = pd.GetStreamDescriptorCount()?;
+        let mut video_stream_descriptor = None;
+        for i in 0..stream_count {
+            let mut selected = Default::default();
+            let mut stream_descriptor = None;
+            pd.GetStreamDescriptorByIndex(i, &mut selected, &mut stream_descriptor)?;
+            let Some(stream_descriptor) = stream_descriptor else {
+                continue;
+            };
+            let handler = stream_descriptor.GetMediaTypeHandler()?;
+            if handler.GetMajorType()? == MFMediaType_Video {
+                video_stream_descriptor = Some(stream_descriptor);
+                break;
+            }
+        }
+        let stream_descriptor = video_stream_descriptor
+            .ok_or_else(|| Error::new(E_FAIL, "No video stream descriptor found".into()))?;
+
+        let handler = stream_descriptor.GetMediaTypeHandler()?;
+        let count = handler.GetMediaTypeCount()?;
+        for i in 0..count {
+            let media_type = handler.GetMediaTypeByIndex(i)?;
+            if let (Some((width, height)), Some(frame_rate)) =
+                (frame_size(&media_type), frame_rate(&media_type))
+            {
+                resolution.insert(CaptureDeviceResolution {
+                    width,
+                    height,
+                    frame_rate,
+                    format: format(&media_type),
+                    min_frame_rate: None,
+                    max_frame_rate: None,
+                });
+            }
+        }
+
+        let _ = source.Shutdown();
+    }
+
+    let mut resolution: Vec<_> = resolution.into_iter().collect();
+    resolution.sort_unstable_by(|a, b| {
+        let a = (a.width as f64) * (a.height as f64) * a.frame_rate;
+        let b = (b.width as f64) * (b.height as f64) * b.frame_rate;
+        b.partial_cmp(&a).unwrap()
+    });
+    Ok(resolution)
+}
+
+/// Get capture devices from Media Foundation.
+///
+/// # Examples
+///
+/// ```rust
+/// use capture_device_info::CaptureDeviceInfo;
+/// // To use Media Foundation API, enable the `mf` feature.
+/// # #[cfg(feature = "mf")]
+/// use capture_device_info::mf::capture_devices;
+///
+/// # #[cfg(feature = "mf")]
+/// # fn main() {
+/// for device in capture_devices().unwrap() {
+///     // ...
+/// }
+/// # }
+/// ```
+pub fn capture_devices() -> Result<impl Iterator<Item = MediaFoundationCaptureDevice>> {
+    capture_devices_impl(None)
+}
+
+/// Like [`capture_devices`], but skips devices matching `options` before probing
+/// their resolutions.
+///
+/// This prevents hangs and duplicate entries when enumerating on machines with
+/// crash-prone or redundant virtual camera software installed (see
+/// [`EnumerationOptions`] for the default blocklist). Because the check happens
+/// before a blocked device's media source is activated and queried, it can skip
+/// a device that would otherwise hang during that probing.
+pub fn capture_devices_filtered(
+    options: &EnumerationOptions,
+) -> Result<impl Iterator<Item = MediaFoundationCaptureDevice>> {
+    capture_devices_impl(Some(options))
+}
+
+fn capture_devices_impl(
+    options: Option<&EnumerationOptions>,
+) -> Result<impl Iterator<Item = MediaFoundationCaptureDevice>> {
+    unsafe { CoInitializeEx(None, COINIT_MULTITHREADED)? };
+    unsafe { MFStartup(MF_VERSION, MFSTARTUP_NOSOCKET)? };
+
+    #[cfg(feature = "position")]
+    let enclosures = crate::enclosure::enumerate_enclosures().unwrap_or_default();
+
+    let mut devices = Vec::new();
+    for activate in DeviceSourceIterator::enumerate_devices()? {
+        let description = get_allocated_string(&activate, &MF_DEVSOURCE_ATTRIBUTE_FRIENDLY_NAME)?;
+        let name = get_allocated_string(
+            &activate,
+            &MF_DEVSOURCE_ATTRIBUTE_SOURCE_TYPE_VIDCAP_SYMBOLIC_LINK,
+        )
+        .unwrap_or_default();
+
+        // skip blocked devices before activating the media source to probe resolutions
+        if let Some(options) = options {
+            let vendor_id = crate::usb_id_from_path(&name, "vid_");
+            let product_id = crate::usb_id_from_path(&name, "pid_");
+            if options.excludes(&name, &description, vendor_id, product_id) {
+                continue;
+            }
+        }
+
+        let resolution = enumerate_resolutions(&activate)?;
+
+        #[cfg(feature = "position")]
+        let (orientation, position) = crate::enclosure::find_enclosure(&enclosures, &name)
+            .map(|e| (e.orientation, e.position))
+            .unwrap_or((None, None));
+
+        devices.push(MediaFoundationCaptureDevice {
+            name,
+            description,
+            resolution,
+            #[cfg(feature = "position")]
+            orientation,
+            #[cfg(feature = "position")]
+            position,
+        });
+    }
+
+    unsafe { MFShutdown()? };
+    unsafe { CoUninitialize() };
+
+    Ok(devices.into_iter())
+}